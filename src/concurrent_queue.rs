@@ -0,0 +1,351 @@
+use std::cell::UnsafeCell;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A bounded, lock-free, multi-producer/multi-consumer queue.
+/// The `const`-parameter `C` denotes the capacity.
+///
+/// This implements Dmitry Vyukov's bounded MPMC array queue: every slot
+/// carries its own sequence number, which producers and consumers use to
+/// claim the slot via a single `compare_exchange_weak` on the shared
+/// `head`/`tail` counters, without ever blocking each other. Unlike
+/// [`Queue`](crate::Queue), `enqueue` and `dequeue` take `&self`, since any
+/// number of threads may call them at once.
+///
+/// `C` must be at least 2: with a single slot, the sequence number a
+/// producer leaves behind after writing is indistinguishable from the one
+/// a consumer would leave after draining it, so the queue could not tell
+/// "full" from "empty".
+pub struct ConcurrentQueue<T, const C: usize> {
+    slots: [Slot<T>; C],
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+struct Slot<T> {
+    sequence: AtomicUsize,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+// SAFETY: access to a slot's `value` is synchronized by its `sequence`
+// counter (a producer only writes after claiming the slot via the `tail`
+// CAS, a consumer only reads after claiming it via the `head` CAS), so
+// sharing a `ConcurrentQueue` across threads is sound whenever `T` is
+// `Send`.
+unsafe impl<T: Send, const C: usize> Sync for ConcurrentQueue<T, C> {}
+
+impl<T, const C: usize> Default for ConcurrentQueue<T, C> {
+    fn default() -> Self {
+        ConcurrentQueue::new()
+    }
+}
+
+impl<T, const C: usize> ConcurrentQueue<T, C> {
+    /// Constructs a new, empty `ConcurrentQueue<T, C>`.
+    ///
+    /// # Panics
+    /// Panics if `C` is less than 2; see the type-level documentation for
+    /// why a single slot can't distinguish "full" from "empty".
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::ConcurrentQueue;
+    ///
+    /// let queue = ConcurrentQueue::<i32, 4>::new();
+    /// ```
+    pub fn new() -> Self {
+        assert!(C >= 2, "ConcurrentQueue capacity must be at least 2");
+        Self {
+            slots: std::array::from_fn(|i| Slot {
+                sequence: AtomicUsize::new(i),
+                value: UnsafeCell::new(MaybeUninit::uninit()),
+            }),
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the number of elements the queue can hold.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::ConcurrentQueue;
+    ///
+    /// let queue = ConcurrentQueue::<i32, 4>::new();
+    /// assert_eq!(queue.capacity(), 4);
+    /// ```
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        C
+    }
+
+    /// Returns a snapshot of the number of elements in the queue.
+    ///
+    /// Under concurrent access the result may be stale by the time it is
+    /// read, or even momentarily inconsistent (a `head` advance observed
+    /// between the two loads below can make it look like more elements were
+    /// dequeued than were ever enqueued); it is meant for diagnostics, not
+    /// for deciding whether `enqueue`/`dequeue` will succeed.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::ConcurrentQueue;
+    ///
+    /// let queue = ConcurrentQueue::<i32, 2>::new();
+    /// assert_eq!(queue.len(), 0);
+    /// queue.enqueue(1).unwrap();
+    /// assert_eq!(queue.len(), 1);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        let tail = self.tail.load(Ordering::Relaxed);
+        let head = self.head.load(Ordering::Relaxed);
+        // `head` can race ahead of a stale `tail` read between the two loads
+        // above, so a plain subtraction could underflow; saturate instead,
+        // since the result is already only an estimate.
+        tail.saturating_sub(head)
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::ConcurrentQueue;
+    ///
+    /// let queue = ConcurrentQueue::<i32, 2>::new();
+    /// assert_eq!(queue.is_empty(), true);
+    /// queue.enqueue(1).unwrap();
+    /// assert_eq!(queue.is_empty(), false);
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the queue cannot contain any more elements.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::ConcurrentQueue;
+    ///
+    /// let queue = ConcurrentQueue::<i32, 2>::new();
+    /// assert_eq!(queue.is_full(), false);
+    /// queue.enqueue(1).unwrap();
+    /// queue.enqueue(2).unwrap();
+    /// assert_eq!(queue.is_full(), true);
+    /// ```
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Insert an element at the back of the queue.
+    /// Returns `Err(element)` if the queue is full.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::ConcurrentQueue;
+    ///
+    /// let queue = ConcurrentQueue::<i32, 2>::new();
+    /// assert_eq!(queue.enqueue(1), Ok(()));
+    /// assert_eq!(queue.enqueue(2), Ok(()));
+    /// assert_eq!(queue.enqueue(3), Err(3));
+    /// ```
+    pub fn enqueue(&self, element: T) -> Result<(), T> {
+        let mut tail = self.tail.load(Ordering::Relaxed);
+        loop {
+            let slot = &self.slots[tail % C];
+            let sequence = slot.sequence.load(Ordering::Acquire);
+            if sequence == tail {
+                // The slot is free; try to claim it.
+                match self.tail.compare_exchange_weak(
+                    tail,
+                    tail + 1,
+                    Ordering::Acquire,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        // SAFETY: claiming the slot via the CAS above gives
+                        // this thread exclusive access until `sequence` is
+                        // published below.
+                        unsafe { (*slot.value.get()).write(element) };
+                        slot.sequence.store(tail + 1, Ordering::Release);
+                        return Ok(());
+                    }
+                    // Another producer claimed `tail` first; retry with the
+                    // counter it advanced to.
+                    Err(current) => tail = current,
+                }
+            } else if sequence < tail {
+                // The slot a full lap behind `tail` hasn't been drained yet.
+                return Err(element);
+            } else {
+                // Another producer has already moved `tail` past our read.
+                tail = self.tail.load(Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Take an element out of the front of the queue.
+    /// Returns `None` if the queue is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::ConcurrentQueue;
+    ///
+    /// let queue = ConcurrentQueue::<i32, 2>::new();
+    /// queue.enqueue(1).unwrap();
+    /// assert_eq!(queue.dequeue(), Some(1));
+    /// assert_eq!(queue.dequeue(), None);
+    /// ```
+    pub fn dequeue(&self) -> Option<T> {
+        let mut head = self.head.load(Ordering::Relaxed);
+        loop {
+            let slot = &self.slots[head % C];
+            let sequence = slot.sequence.load(Ordering::Acquire);
+            if sequence == head + 1 {
+                // The slot has been published by a producer; try to claim it.
+                match self.head.compare_exchange_weak(
+                    head,
+                    head + 1,
+                    Ordering::Acquire,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        // SAFETY: claiming the slot via the CAS above gives
+                        // this thread exclusive access to the value written
+                        // by `enqueue`.
+                        let element = unsafe { (*slot.value.get()).assume_init_read() };
+                        slot.sequence.store(head + C, Ordering::Release);
+                        return Some(element);
+                    }
+                    // Another consumer claimed `head` first; retry with the
+                    // counter it advanced to.
+                    Err(current) => head = current,
+                }
+            } else if sequence <= head {
+                // The slot hasn't been published yet; the queue is empty.
+                return None;
+            } else {
+                // Another consumer has already moved `head` past our read.
+                head = self.head.load(Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+impl<T, const C: usize> Drop for ConcurrentQueue<T, C> {
+    fn drop(&mut self) {
+        let head = *self.head.get_mut();
+        let tail = *self.tail.get_mut();
+        for i in head..tail {
+            let slot = &mut self.slots[i % C];
+            // SAFETY: slots `head..tail` were claimed and written by
+            // `enqueue` but never drained by `dequeue`.
+            unsafe { (*slot.value.get()).assume_init_drop() };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConcurrentQueue;
+
+    #[test]
+    fn new() {
+        let queue = ConcurrentQueue::<usize, 5>::new();
+        assert_eq!(queue.capacity(), 5);
+        assert_eq!(queue.len(), 0);
+        assert_eq!(queue.is_empty(), true);
+        assert_eq!(queue.is_full(), false);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity must be at least 2")]
+    fn new_rejects_capacity_below_two() {
+        let _ = ConcurrentQueue::<usize, 1>::new();
+    }
+
+    #[test]
+    fn enqueue_dequeue_fifo() {
+        let queue = ConcurrentQueue::<usize, 2>::new();
+        assert_eq!(queue.enqueue(1), Ok(()));
+        assert_eq!(queue.enqueue(2), Ok(()));
+        assert_eq!(queue.is_full(), true);
+        assert_eq!(queue.enqueue(3), Err(3));
+
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.is_empty(), true);
+    }
+
+    #[test]
+    fn wraps_around_the_backing_array() {
+        let queue = ConcurrentQueue::<usize, 2>::new();
+        for round in 0..3 {
+            assert_eq!(queue.enqueue(round), Ok(()));
+            assert_eq!(queue.enqueue(round + 100), Ok(()));
+            assert_eq!(queue.dequeue(), Some(round));
+            assert_eq!(queue.dequeue(), Some(round + 100));
+        }
+    }
+
+    #[test]
+    fn drops_non_copy_elements() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let queue = ConcurrentQueue::<Rc<()>, 2>::new();
+        assert_eq!(queue.enqueue(value.clone()), Ok(()));
+        assert_eq!(queue.enqueue(value.clone()), Ok(()));
+        assert_eq!(queue.dequeue().is_some(), true);
+        assert_eq!(Rc::strong_count(&value), 2);
+
+        drop(queue);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn concurrent_producers_and_consumers() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::thread;
+
+        const PRODUCERS: usize = 4;
+        const PER_PRODUCER: usize = 1000;
+
+        let queue = ConcurrentQueue::<usize, 16>::new();
+        let produced = AtomicUsize::new(0);
+
+        thread::scope(|scope| {
+            for p in 0..PRODUCERS {
+                let queue = &queue;
+                scope.spawn(move || {
+                    for i in 0..PER_PRODUCER {
+                        let value = p * PER_PRODUCER + i;
+                        while queue.enqueue(value).is_err() {
+                            thread::yield_now();
+                        }
+                    }
+                });
+            }
+
+            let produced = &produced;
+            for _ in 0..2 {
+                let queue = &queue;
+                scope.spawn(move || {
+                    while produced.load(Ordering::Relaxed) < PRODUCERS * PER_PRODUCER {
+                        if queue.dequeue().is_some() {
+                            produced.fetch_add(1, Ordering::Relaxed);
+                        } else {
+                            thread::yield_now();
+                        }
+                    }
+                });
+            }
+        });
+
+        assert_eq!(produced.load(Ordering::Relaxed), PRODUCERS * PER_PRODUCER);
+        assert_eq!(queue.is_empty(), true);
+    }
+}
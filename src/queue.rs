@@ -1,13 +1,26 @@
-/// A fixed-size queue.
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::sync::atomic::{AtomicIsize, Ordering};
+
+/// A fixed-size queue, usable as a double-ended ring buffer.
 /// The `const`-parameter `C` denotes the capacity.
-#[derive(Clone, Copy, Debug)]
+///
+/// `head` and `tail` are unbounded counters rather than indices wrapped
+/// into `0..C`; the physical slot for a counter value is
+/// `counter.rem_euclid(C)`. Besides letting [`Queue::split`] tell a full
+/// queue (`tail - head == C`) apart from an empty one (`head == tail`)
+/// without sacrificing a slot or a separate length field, it lets the
+/// counters move in either direction, which is what makes `push_front` and
+/// `pop_back` possible alongside the single-ended operations.
 pub struct Queue<T, const C: usize> {
-    elements: [Option<T>; C],
-    head: usize,
-    length: usize,
+    elements: [MaybeUninit<T>; C],
+    head: AtomicIsize,
+    tail: AtomicIsize,
 }
 
-impl<T: Copy, const C: usize> Default for Queue<T, C> {
+impl<T, const C: usize> Default for Queue<T, C> {
     fn default() -> Self {
         Queue::new()
     }
@@ -22,15 +35,13 @@ impl<T, const C: usize> Queue<T, C> {
     ///
     /// let queue = Queue::<i32, 4>::new();
     /// ```
-    pub fn new() -> Self
-    where
-        T: Copy,
-    {
-        let elements: [Option<T>; C] = [None; C];
+    pub const fn new() -> Self {
         Self {
-            elements,
-            head: 0,
-            length: 0,
+            // SAFETY: an array of `MaybeUninit<T>` does not require
+            // initialization; `head`/`tail` track which slots are live.
+            elements: unsafe { MaybeUninit::uninit().assume_init() },
+            head: AtomicIsize::new(0),
+            tail: AtomicIsize::new(0),
         }
     }
 
@@ -60,8 +71,8 @@ impl<T, const C: usize> Queue<T, C> {
     /// assert_eq!(queue.len(), 1);
     /// ```
     #[inline]
-    pub const fn len(&self) -> usize {
-        self.length
+    pub fn len(&self) -> usize {
+        (self.tail.load(Ordering::Relaxed) - self.head.load(Ordering::Relaxed)) as usize
     }
 
     /// Returns `true` if the queue contains no elements.
@@ -76,7 +87,7 @@ impl<T, const C: usize> Queue<T, C> {
     /// assert_eq!(queue.is_empty(), false);
     /// ```
     #[inline]
-    pub const fn is_empty(&self) -> bool {
+    pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
 
@@ -92,7 +103,7 @@ impl<T, const C: usize> Queue<T, C> {
     /// assert_eq!(queue.is_full(), true);
     /// ```
     #[inline]
-    pub const fn is_full(&self) -> bool {
+    pub fn is_full(&self) -> bool {
         self.len() == self.capacity()
     }
 
@@ -110,40 +121,198 @@ impl<T, const C: usize> Queue<T, C> {
     /// assert_eq!(queue.get(0), Some(&1));
     /// ```
     #[inline]
-    pub const fn get(&self, index: usize) -> Option<&T> {
+    pub fn get(&self, index: usize) -> Option<&T> {
         // If the index is greater than or equal to `len`,
         // then the computed index would wrap around more
         // than once, making it incorrect.
         if index >= self.len() {
             return None;
         }
-        self.elements[(self.head + index) % self.capacity()].as_ref()
+        let i = Self::physical(self.head.load(Ordering::Relaxed) + index as isize);
+        // SAFETY: `index < self.len()`, so this slot is live.
+        Some(unsafe { self.elements[i].assume_init_ref() })
+    }
+
+    /// Returns a reference to the element at the front of the queue.
+    /// Returns `None` if the queue is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::Queue;
+    ///
+    /// let mut queue = Queue::<i32, 1>::new();
+    /// assert_eq!(queue.front(), None);
+    /// queue.enqueue(1);
+    /// assert_eq!(queue.front(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the element at the back of the queue.
+    /// Returns `None` if the queue is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::Queue;
+    ///
+    /// let mut queue = Queue::<i32, 1>::new();
+    /// assert_eq!(queue.back(), None);
+    /// queue.enqueue(1);
+    /// assert_eq!(queue.back(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len().checked_sub(1)?)
     }
 
     /// Returns the index of the first occupied slot in the queue.
     #[inline]
-    const fn head(&self) -> usize {
-        self.head
+    fn head(&self) -> usize {
+        Self::physical(self.head.load(Ordering::Relaxed))
     }
 
     /// Returns the index of the first empty slot in the queue.
+    #[cfg(test)]
     #[inline]
-    const fn tail(&self) -> usize {
-        (self.head + self.len()) % self.capacity()
+    fn tail(&self) -> usize {
+        Self::physical(self.tail.load(Ordering::Relaxed))
     }
 
-    /// Returns a reference to the underlying storage of the queue.
+    /// Maps an unbounded `head`/`tail` counter to its physical slot.
+    #[inline]
+    fn physical(counter: isize) -> usize {
+        counter.rem_euclid(C as isize) as usize
+    }
+
+    /// Returns a slice of the initialized elements in the queue, front to back.
+    ///
+    /// If the queue has wrapped around the end of its backing storage, only
+    /// the contiguous run starting at the front is returned; use
+    /// [`as_slices`](Self::as_slices) to also get the wrapped remainder.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::Queue;
+    ///
+    /// let mut queue = Queue::<i32, 3>::new();
+    /// assert_eq!(queue.as_slice(), &[]);
+    /// let _ = queue.enqueue(1);
+    /// assert_eq!(queue.as_slice(), &[1]);
+    /// let _ = queue.enqueue(2);
+    /// assert_eq!(queue.as_slice(), &[1, 2]);
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        let head = self.head();
+        let contiguous = self.capacity() - head;
+        let len = self.len();
+        let count = if len < contiguous { len } else { contiguous };
+        // SAFETY: slots `head..head + count` are live and contiguous.
+        unsafe { std::slice::from_raw_parts(self.elements.as_ptr().add(head) as *const T, count) }
+    }
+
+    /// Returns the two contiguous runs of initialized elements, front to back.
+    ///
+    /// The first slice starts at the front of the queue and runs to the end
+    /// of the backing array; the second picks up at the start of the array
+    /// and holds whatever wrapped around, exactly as [`VecDeque::as_slices`]
+    /// does. The second slice is empty unless the queue has wrapped.
+    ///
+    /// [`VecDeque::as_slices`]: std::collections::VecDeque::as_slices
     ///
     /// # Example
     /// ```
     /// use fundamental::Queue;
     ///
     /// let mut queue = Queue::<i32, 3>::new();
-    /// assert_eq!(queue.as_slice(), &[None, None, None]);
+    /// let _ = queue.push_back(1);
+    /// let _ = queue.push_back(2);
+    /// let _ = queue.pop_front();
+    /// let _ = queue.push_back(3);
+    /// let _ = queue.push_back(4);
+    /// assert_eq!(queue.as_slices(), (&[2, 3][..], &[4][..]));
+    /// ```
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let head = self.head();
+        let len = self.len();
+        let contiguous = self.capacity() - head;
+        if len <= contiguous {
+            (self.as_slice(), &[])
+        } else {
+            let second_len = len - contiguous;
+            // SAFETY: slots `0..second_len` are the live, wrapped remainder.
+            let second = unsafe {
+                std::slice::from_raw_parts(self.elements.as_ptr() as *const T, second_len)
+            };
+            (self.as_slice(), second)
+        }
+    }
+
+    /// Returns an iterator over references to the elements in the queue,
+    /// front to back.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::Queue;
+    ///
+    /// let mut queue = Queue::<i32, 2>::new();
+    /// let _ = queue.push_back(1);
+    /// let _ = queue.push_back(2);
+    /// assert_eq!(queue.iter().collect::<Vec<_>>(), vec![&1, &2]);
     /// ```
     #[inline]
-    pub const fn as_slice(&self) -> &[Option<T>] {
-        &self.elements
+    pub fn iter(&self) -> Iter<'_, T, C> {
+        Iter::new(self)
+    }
+
+    /// Returns an iterator over mutable references to the elements in the
+    /// queue, front to back.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::Queue;
+    ///
+    /// let mut queue = Queue::<i32, 2>::new();
+    /// let _ = queue.push_back(1);
+    /// let _ = queue.push_back(2);
+    /// for element in queue.iter_mut() {
+    ///     *element += 1;
+    /// }
+    /// assert_eq!(queue.iter().collect::<Vec<_>>(), vec![&2, &3]);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, C> {
+        IterMut::new(self)
+    }
+
+    /// Splits the queue into a [`Producer`] that can only enqueue and a
+    /// [`Consumer`] that can only dequeue, so one thread can feed the queue
+    /// while another drains it without a lock.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::Queue;
+    ///
+    /// let mut queue = Queue::<i32, 2>::new();
+    /// let (mut producer, mut consumer) = queue.split();
+    /// assert_eq!(producer.enqueue(1), Ok(()));
+    /// assert_eq!(consumer.dequeue(), Some(1));
+    /// ```
+    pub fn split(&mut self) -> (Producer<'_, T, C>, Consumer<'_, T, C>) {
+        let queue: *mut Self = self;
+        (
+            Producer {
+                queue,
+                _marker: PhantomData,
+            },
+            Consumer {
+                queue,
+                _marker: PhantomData,
+            },
+        )
     }
 
     /// Insert an element at the back of the queue.
@@ -154,20 +323,17 @@ impl<T, const C: usize> Queue<T, C> {
     /// use fundamental::Queue;
     ///
     /// let mut queue = Queue::<i32, 3>::new();
-    /// assert_eq!(queue.as_slice(), &[None, None, None]);
+    /// assert_eq!(queue.as_slice(), &[]);
     /// let _ = queue.enqueue(1);
-    /// assert_eq!(queue.as_slice(), &[Some(1), None, None]);
+    /// assert_eq!(queue.as_slice(), &[1]);
     /// let _ = queue.enqueue(2);
-    /// assert_eq!(queue.as_slice(), &[Some(1), Some(2), None]);
+    /// assert_eq!(queue.as_slice(), &[1, 2]);
     /// ```
     #[inline]
     pub fn enqueue(&mut self, element: T) -> Result<(), T> {
-        if self.is_full() {
-            return Err(element);
-        }
-        self.elements[self.tail()] = Some(element);
-        self.length += 1;
-        Ok(())
+        // SAFETY: `self` is exclusively borrowed, so there is no concurrent
+        // access to race with.
+        unsafe { Self::enqueue_raw(self, element) }
     }
 
     /// Take an element out of the front of the queue.
@@ -188,12 +354,459 @@ impl<T, const C: usize> Queue<T, C> {
     /// ```
     #[inline]
     pub fn dequeue(&mut self) -> Option<T> {
-        let element = self.elements[self.head()].take();
-        if element.is_some() {
-            self.head = (self.head + 1) % self.capacity();
-            self.length -= 1;
+        // SAFETY: `self` is exclusively borrowed, so there is no concurrent
+        // access to race with.
+        unsafe { Self::dequeue_raw(self) }
+    }
+
+    /// Insert an element at the back of the queue.
+    /// Returns `Err(element)` if the queue is full.
+    ///
+    /// An alias of [`enqueue`](Self::enqueue), named to match [`push_front`](Self::push_front).
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::Queue;
+    ///
+    /// let mut queue = Queue::<i32, 2>::new();
+    /// assert_eq!(queue.push_back(1), Ok(()));
+    /// assert_eq!(queue.as_slice(), &[1]);
+    /// ```
+    #[inline]
+    pub fn push_back(&mut self, element: T) -> Result<(), T> {
+        self.enqueue(element)
+    }
+
+    /// Insert an element at the front of the queue.
+    /// Returns `Err(element)` if the queue is full.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::Queue;
+    ///
+    /// let mut queue = Queue::<i32, 2>::new();
+    /// assert_eq!(queue.push_back(2), Ok(()));
+    /// assert_eq!(queue.push_front(1), Ok(()));
+    /// assert_eq!(queue.front(), Some(&1));
+    /// assert_eq!(queue.back(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn push_front(&mut self, element: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(element);
+        }
+        let head = self.head.load(Ordering::Relaxed) - 1;
+        let index = Self::physical(head);
+        // SAFETY: the queue is not full, so slot `index` is unoccupied.
+        unsafe { ptr::write(self.elements[index].as_mut_ptr(), element) };
+        self.head.store(head, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Take an element out of the front of the queue.
+    /// Returns `None` if the queue is empty.
+    ///
+    /// An alias of [`dequeue`](Self::dequeue), named to match [`pop_back`](Self::pop_back).
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::Queue;
+    ///
+    /// let mut queue = Queue::<i32, 2>::new();
+    /// queue.push_back(1);
+    /// assert_eq!(queue.pop_front(), Some(1));
+    /// ```
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.dequeue()
+    }
+
+    /// Take an element out of the back of the queue.
+    /// Returns `None` if the queue is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::Queue;
+    ///
+    /// let mut queue = Queue::<i32, 2>::new();
+    /// queue.push_back(1);
+    /// queue.push_back(2);
+    /// assert_eq!(queue.pop_back(), Some(2));
+    /// assert_eq!(queue.pop_back(), Some(1));
+    /// assert_eq!(queue.pop_back(), None);
+    /// ```
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
         }
-        element
+        let tail = self.tail.load(Ordering::Relaxed) - 1;
+        let index = Self::physical(tail);
+        // SAFETY: the queue is not empty, so slot `index` is occupied.
+        let element = unsafe { self.elements[index].assume_init_read() };
+        self.tail.store(tail, Ordering::Relaxed);
+        Some(element)
+    }
+
+    /// Enqueues through a raw pointer, shared by [`Queue::enqueue`] and
+    /// [`Producer::enqueue`].
+    ///
+    /// # Safety
+    /// `queue` must be valid for reads and writes, and the caller must be
+    /// the only writer of `tail` (the single-producer invariant).
+    unsafe fn enqueue_raw(queue: *mut Self, element: T) -> Result<(), T> {
+        let tail = (*queue).tail.load(Ordering::Relaxed);
+        let head = (*queue).head.load(Ordering::Acquire);
+        if tail - head == C as isize {
+            return Err(element);
+        }
+        let index = Self::physical(tail);
+        ptr::write((*queue).elements[index].as_mut_ptr(), element);
+        (*queue).tail.store(tail + 1, Ordering::Release);
+        Ok(())
+    }
+
+    /// Dequeues through a raw pointer, shared by [`Queue::dequeue`] and
+    /// [`Consumer::dequeue`].
+    ///
+    /// # Safety
+    /// `queue` must be valid for reads and writes, and the caller must be
+    /// the only writer of `head` (the single-consumer invariant).
+    unsafe fn dequeue_raw(queue: *mut Self) -> Option<T> {
+        let head = (*queue).head.load(Ordering::Relaxed);
+        let tail = (*queue).tail.load(Ordering::Acquire);
+        if head == tail {
+            return None;
+        }
+        let index = Self::physical(head);
+        let element = (*queue).elements[index].assume_init_read();
+        (*queue).head.store(head + 1, Ordering::Release);
+        Some(element)
+    }
+
+    /// Builds a queue by enqueuing elements from `iter` until either it is
+    /// exhausted or the queue is full, returning the queue alongside
+    /// whatever is left of the iterator.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::Queue;
+    ///
+    /// let (queue, mut rest) = Queue::<i32, 2>::try_from_iter(1..4);
+    /// assert_eq!(queue.as_slice(), &[1, 2]);
+    /// assert_eq!(rest.next(), Some(3));
+    /// ```
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> (Self, I::IntoIter) {
+        let mut queue = Self::new();
+        let mut iter = iter.into_iter();
+        while !queue.is_full() {
+            let Some(element) = iter.next() else {
+                break;
+            };
+            // `queue` isn't full, so this always succeeds.
+            let _ = queue.enqueue(element);
+        }
+        (queue, iter)
+    }
+}
+
+impl<T, const C: usize> Drop for Queue<T, C> {
+    fn drop(&mut self) {
+        let head = *self.head.get_mut();
+        let tail = *self.tail.get_mut();
+        for i in head..tail {
+            let index = Self::physical(i);
+            // SAFETY: slots `head..tail` are live.
+            unsafe { self.elements[index].assume_init_drop() };
+        }
+    }
+}
+
+impl<T: Clone, const C: usize> Clone for Queue<T, C> {
+    fn clone(&self) -> Self {
+        let mut queue = Self::new();
+        let (first, second) = self.as_slices();
+        for element in first.iter().chain(second) {
+            // `self` has at most `C` elements, so this always succeeds.
+            let _ = queue.enqueue(element.clone());
+        }
+        queue
+    }
+}
+
+impl<T: fmt::Debug, const C: usize> fmt::Debug for Queue<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Queue")
+            .field("elements", &self.as_slices())
+            .field("head", &self.head())
+            .field("length", &self.len())
+            .finish()
+    }
+}
+
+impl<T: Clone, const C: usize> Queue<T, C> {
+    /// Enqueues as many of `elements` as fit, in order, and returns the
+    /// unconsumed tail once the queue is full.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::Queue;
+    ///
+    /// let mut queue = Queue::<i32, 2>::new();
+    /// assert_eq!(queue.enqueue_slice(&[1, 2, 3]), &[3]);
+    /// assert_eq!(queue.as_slice(), &[1, 2]);
+    /// ```
+    pub fn enqueue_slice<'a>(&mut self, elements: &'a [T]) -> &'a [T] {
+        let mut consumed = 0;
+        for element in elements {
+            if self.enqueue(element.clone()).is_err() {
+                break;
+            }
+            consumed += 1;
+        }
+        &elements[consumed..]
+    }
+}
+
+impl<T, const C: usize> FromIterator<T> for Queue<T, C> {
+    /// Collects up to `C` elements from `iter`, in order; anything beyond
+    /// capacity is silently dropped. Use [`Queue::try_from_iter`] to get the
+    /// leftover iterator back instead.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::try_from_iter(iter).0
+    }
+}
+
+impl<T, const C: usize> Extend<T> for Queue<T, C> {
+    /// Enqueues elements from `iter` until either it is exhausted or the
+    /// queue is full; anything beyond capacity is silently dropped.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for element in iter {
+            if self.enqueue(element).is_err() {
+                break;
+            }
+        }
+    }
+}
+
+/// The write half of a [`Queue`] produced by [`Queue::split`].
+///
+/// A `Producer` only ever advances `tail`, so it can run on a different
+/// thread than its paired [`Consumer`] without a lock.
+pub struct Producer<'a, T, const C: usize> {
+    queue: *mut Queue<T, C>,
+    _marker: PhantomData<&'a mut Queue<T, C>>,
+}
+
+/// The read half of a [`Queue`] produced by [`Queue::split`].
+///
+/// A `Consumer` only ever advances `head`, so it can run on a different
+/// thread than its paired [`Producer`] without a lock.
+pub struct Consumer<'a, T, const C: usize> {
+    queue: *mut Queue<T, C>,
+    _marker: PhantomData<&'a mut Queue<T, C>>,
+}
+
+// SAFETY: a `Producer` only ever writes `tail` (it only reads `head`) and
+// writes slots it alone claims via `tail`; sending it to another thread just relocates those
+// writes, which is sound whenever `T` itself is `Send`.
+unsafe impl<T: Send, const C: usize> Send for Producer<'_, T, C> {}
+
+// SAFETY: symmetric to the `Producer` impl above, but for `head`.
+unsafe impl<T: Send, const C: usize> Send for Consumer<'_, T, C> {}
+
+impl<T, const C: usize> Producer<'_, T, C> {
+    /// Insert an element at the back of the queue.
+    /// Returns `Err(element)` if the queue is full.
+    #[inline]
+    pub fn enqueue(&mut self, element: T) -> Result<(), T> {
+        // SAFETY: only the paired `Consumer` may also access the queue, and
+        // it never writes `tail`.
+        unsafe { Queue::enqueue_raw(self.queue, element) }
+    }
+}
+
+impl<T, const C: usize> Consumer<'_, T, C> {
+    /// Take an element out of the front of the queue.
+    /// Returns `None` if the queue is empty.
+    #[inline]
+    pub fn dequeue(&mut self) -> Option<T> {
+        // SAFETY: only the paired `Producer` may also access the queue, and
+        // it never writes `head`.
+        unsafe { Queue::dequeue_raw(self.queue) }
+    }
+}
+
+/// An iterator over references to the elements of a [`Queue`], front to
+/// back. Obtained via [`Queue::iter`] or `(&queue).into_iter()`.
+pub struct Iter<'a, T, const C: usize> {
+    queue: &'a Queue<T, C>,
+    front: isize,
+    back: isize,
+}
+
+impl<'a, T, const C: usize> Iter<'a, T, C> {
+    fn new(queue: &'a Queue<T, C>) -> Self {
+        Self {
+            front: queue.head.load(Ordering::Relaxed),
+            back: queue.tail.load(Ordering::Relaxed),
+            queue,
+        }
+    }
+}
+
+impl<'a, T, const C: usize> Iterator for Iter<'a, T, C> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let index = Queue::<T, C>::physical(self.front);
+        self.front += 1;
+        // SAFETY: `index` falls within the live `head..tail` range and is
+        // yielded at most once across `next`/`next_back`.
+        Some(unsafe { self.queue.elements[index].assume_init_ref() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, const C: usize> DoubleEndedIterator for Iter<'_, T, C> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        let index = Queue::<T, C>::physical(self.back);
+        // SAFETY: `index` falls within the live `head..tail` range and is
+        // yielded at most once across `next`/`next_back`.
+        Some(unsafe { self.queue.elements[index].assume_init_ref() })
+    }
+}
+
+impl<T, const C: usize> ExactSizeIterator for Iter<'_, T, C> {
+    fn len(&self) -> usize {
+        (self.back - self.front) as usize
+    }
+}
+
+/// An iterator over mutable references to the elements of a [`Queue`],
+/// front to back. Obtained via [`Queue::iter_mut`] or
+/// `(&mut queue).into_iter()`.
+pub struct IterMut<'a, T, const C: usize> {
+    elements: *mut MaybeUninit<T>,
+    front: isize,
+    back: isize,
+    _marker: PhantomData<&'a mut Queue<T, C>>,
+}
+
+impl<'a, T, const C: usize> IterMut<'a, T, C> {
+    fn new(queue: &'a mut Queue<T, C>) -> Self {
+        Self {
+            elements: queue.elements.as_mut_ptr(),
+            front: *queue.head.get_mut(),
+            back: *queue.tail.get_mut(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, const C: usize> Iterator for IterMut<'a, T, C> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let index = Queue::<T, C>::physical(self.front);
+        self.front += 1;
+        // SAFETY: `index` falls within the live `head..tail` range and is
+        // yielded at most once across `next`/`next_back`, so no two calls
+        // ever alias the same slot.
+        Some(unsafe { (*self.elements.add(index)).assume_init_mut() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, const C: usize> DoubleEndedIterator for IterMut<'_, T, C> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        let index = Queue::<T, C>::physical(self.back);
+        // SAFETY: see `next`.
+        Some(unsafe { (*self.elements.add(index)).assume_init_mut() })
+    }
+}
+
+impl<T, const C: usize> ExactSizeIterator for IterMut<'_, T, C> {
+    fn len(&self) -> usize {
+        (self.back - self.front) as usize
+    }
+}
+
+/// An owning iterator over the elements of a [`Queue`], front to back.
+/// Obtained via `queue.into_iter()`.
+pub struct IntoIter<T, const C: usize>(Queue<T, C>);
+
+impl<T, const C: usize> Iterator for IntoIter<T, C> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, const C: usize> DoubleEndedIterator for IntoIter<T, C> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<T, const C: usize> ExactSizeIterator for IntoIter<T, C> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T, const C: usize> IntoIterator for Queue<T, C> {
+    type Item = T;
+    type IntoIter = IntoIter<T, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T, const C: usize> IntoIterator for &'a Queue<T, C> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter::new(self)
+    }
+}
+
+impl<'a, T, const C: usize> IntoIterator for &'a mut Queue<T, C> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut::new(self)
     }
 }
 
@@ -257,4 +870,233 @@ mod tests {
         assert_eq!(queue.is_empty(), true);
         assert_eq!(queue.is_full(), false);
     }
+
+    #[test]
+    fn drops_non_copy_elements() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let mut queue = Queue::<Rc<()>, 2>::new();
+        assert_eq!(queue.enqueue(value.clone()), Ok(()));
+        assert_eq!(queue.enqueue(value.clone()), Ok(()));
+        assert_eq!(queue.dequeue().is_some(), true);
+        assert_eq!(Rc::strong_count(&value), 2);
+
+        drop(queue);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn holds_non_copy_elements() {
+        let mut queue = Queue::<String, 2>::new();
+        assert_eq!(queue.enqueue(String::from("a")), Ok(()));
+        assert_eq!(queue.enqueue(String::from("b")), Ok(()));
+        assert_eq!(queue.dequeue(), Some(String::from("a")));
+        assert_eq!(queue.dequeue(), Some(String::from("b")));
+    }
+
+    #[test]
+    fn split_spsc() {
+        let mut queue = Queue::<usize, 2>::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        assert_eq!(producer.enqueue(1), Ok(()));
+        assert_eq!(producer.enqueue(2), Ok(()));
+        assert_eq!(producer.enqueue(3), Err(3));
+
+        assert_eq!(consumer.dequeue(), Some(1));
+        assert_eq!(consumer.dequeue(), Some(2));
+        assert_eq!(consumer.dequeue(), None);
+    }
+
+    #[test]
+    fn split_across_threads() {
+        use std::thread;
+
+        let mut queue = Queue::<usize, 4>::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                for i in 0..1000 {
+                    while producer.enqueue(i).is_err() {
+                        thread::yield_now();
+                    }
+                }
+            });
+            let received = scope
+                .spawn(move || {
+                    let mut received = Vec::with_capacity(1000);
+                    while received.len() < 1000 {
+                        if let Some(i) = consumer.dequeue() {
+                            received.push(i);
+                        } else {
+                            thread::yield_now();
+                        }
+                    }
+                    received
+                })
+                .join()
+                .unwrap();
+            assert_eq!(received, (0..1000).collect::<Vec<_>>());
+        });
+    }
+
+    #[test]
+    fn deque_front_and_back() {
+        let mut queue = Queue::<i32, 3>::new();
+        assert_eq!(queue.front(), None);
+        assert_eq!(queue.back(), None);
+
+        assert_eq!(queue.push_back(2), Ok(()));
+        assert_eq!(queue.push_back(3), Ok(()));
+        assert_eq!(queue.push_front(1), Ok(()));
+        assert_eq!(queue.get(0), Some(&1));
+        assert_eq!(queue.get(1), Some(&2));
+        assert_eq!(queue.get(2), Some(&3));
+        assert_eq!(queue.front(), Some(&1));
+        assert_eq!(queue.back(), Some(&3));
+
+        assert_eq!(queue.push_front(0), Err(0));
+
+        assert_eq!(queue.pop_back(), Some(3));
+        assert_eq!(queue.pop_front(), Some(1));
+        assert_eq!(queue.pop_front(), Some(2));
+        assert_eq!(queue.pop_back(), None);
+    }
+
+    #[test]
+    fn deque_wraps_both_ways() {
+        let mut queue = Queue::<i32, 3>::new();
+        assert_eq!(queue.push_back(1), Ok(()));
+        assert_eq!(queue.pop_front(), Some(1));
+
+        // `head`/`tail` have now moved past their starting point, so
+        // `push_front` must wrap backwards correctly too.
+        assert_eq!(queue.push_front(2), Ok(()));
+        assert_eq!(queue.push_front(1), Ok(()));
+        assert_eq!(queue.push_back(3), Ok(()));
+        assert_eq!(queue.get(0), Some(&1));
+        assert_eq!(queue.get(1), Some(&2));
+        assert_eq!(queue.get(2), Some(&3));
+    }
+
+    #[test]
+    fn as_slices_reports_both_runs() {
+        let mut queue = Queue::<i32, 3>::new();
+        assert_eq!(queue.as_slices(), (&[][..], &[][..]));
+
+        assert_eq!(queue.push_back(1), Ok(()));
+        assert_eq!(queue.push_back(2), Ok(()));
+        assert_eq!(queue.as_slices(), (&[1, 2][..], &[][..]));
+
+        assert_eq!(queue.pop_front(), Some(1));
+        assert_eq!(queue.push_back(3), Ok(()));
+        assert_eq!(queue.push_back(4), Ok(()));
+        assert_eq!(queue.as_slices(), (&[2, 3][..], &[4][..]));
+    }
+
+    #[test]
+    fn clone_preserves_wrapped_elements() {
+        let mut queue = Queue::<i32, 3>::new();
+        assert_eq!(queue.push_back(1), Ok(()));
+        assert_eq!(queue.push_back(2), Ok(()));
+        assert_eq!(queue.pop_front(), Some(1));
+        assert_eq!(queue.push_back(3), Ok(()));
+        assert_eq!(queue.push_back(4), Ok(()));
+        assert_eq!(queue.as_slices(), (&[2, 3][..], &[4][..]));
+
+        let clone = queue.clone();
+        assert_eq!(clone.as_slices(), (&[2, 3, 4][..], &[][..]));
+    }
+
+    #[test]
+    fn iter_front_to_back() {
+        let mut queue = Queue::<i32, 3>::new();
+        assert_eq!(queue.push_back(1), Ok(()));
+        assert_eq!(queue.push_back(2), Ok(()));
+        assert_eq!(queue.pop_front(), Some(1));
+        assert_eq!(queue.push_back(3), Ok(()));
+        assert_eq!(queue.push_back(4), Ok(()));
+
+        assert_eq!(queue.iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+        assert_eq!(queue.iter().len(), 3);
+        assert_eq!(queue.iter().rev().collect::<Vec<_>>(), vec![&4, &3, &2]);
+    }
+
+    #[test]
+    fn iter_mut_updates_in_place() {
+        let mut queue = Queue::<i32, 2>::new();
+        assert_eq!(queue.push_back(1), Ok(()));
+        assert_eq!(queue.push_back(2), Ok(()));
+
+        for element in queue.iter_mut() {
+            *element *= 10;
+        }
+        assert_eq!(queue.iter().collect::<Vec<_>>(), vec![&10, &20]);
+    }
+
+    #[test]
+    fn into_iter_is_double_ended() {
+        let mut queue = Queue::<i32, 3>::new();
+        assert_eq!(queue.push_back(1), Ok(()));
+        assert_eq!(queue.push_back(2), Ok(()));
+        assert_eq!(queue.push_back(3), Ok(()));
+
+        let mut iter = queue.into_iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn into_iterator_for_reference_types() {
+        let mut queue = Queue::<i32, 2>::new();
+        assert_eq!(queue.push_back(1), Ok(()));
+        assert_eq!(queue.push_back(2), Ok(()));
+
+        let mut collected = Vec::new();
+        for element in &queue {
+            collected.push(*element);
+        }
+        assert_eq!(collected, vec![1, 2]);
+
+        for element in &mut queue {
+            *element += 1;
+        }
+
+        let collected: Vec<i32> = queue.into_iter().collect();
+        assert_eq!(collected, vec![2, 3]);
+    }
+
+    #[test]
+    fn enqueue_slice_stops_at_capacity() {
+        let mut queue = Queue::<i32, 2>::new();
+        assert_eq!(queue.enqueue_slice(&[1, 2, 3]), &[3]);
+        assert_eq!(queue.as_slice(), &[1, 2]);
+        assert_eq!(queue.enqueue_slice(&[4]), &[4]);
+    }
+
+    #[test]
+    fn try_from_iter_returns_leftovers() {
+        let (queue, mut rest) = Queue::<i32, 2>::try_from_iter(1..4);
+        assert_eq!(queue.as_slice(), &[1, 2]);
+        assert_eq!(rest.next(), Some(3));
+        assert_eq!(rest.next(), None);
+    }
+
+    #[test]
+    fn from_iter_drops_the_overflow() {
+        let queue: Queue<i32, 2> = (1..4).collect();
+        assert_eq!(queue.as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn extend_stops_at_capacity() {
+        let mut queue = Queue::<i32, 2>::new();
+        queue.extend(1..4);
+        assert_eq!(queue.as_slice(), &[1, 2]);
+    }
 }
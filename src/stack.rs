@@ -1,12 +1,27 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::ptr;
+
 /// A fixed-size stack.
 /// The `const`-parameter `C` denotes the capacity.
-#[derive(Clone, Copy, Debug)]
 pub struct Stack<T, const C: usize> {
-    elements: [Option<T>; C],
+    elements: [MaybeUninit<T>; C],
     length: usize,
+    /// A single buffer shared by every active snapshot level: level `i`'s
+    /// clone occupies `snapshot_storage[snapshot_offsets[i]..][..snapshot_lengths[i]]`,
+    /// with later levels appended right after earlier ones. `snapshot`
+    /// refuses to grow `reserved` past `C`, so the total space used here
+    /// across every level, at any depth of nesting, never exceeds `C`.
+    snapshot_storage: [MaybeUninit<T>; C],
+    snapshot_offsets: [usize; C],
+    snapshot_lengths: [usize; C],
+    snapshots_length: usize,
+    /// Total elements currently held in `snapshot_storage` across all active
+    /// levels; equivalently, the next free offset in that buffer.
+    reserved: usize,
 }
 
-impl<T: Copy, const C: usize> Default for Stack<T, C> {
+impl<T, const C: usize> Default for Stack<T, C> {
     fn default() -> Self {
         Stack::new()
     }
@@ -21,14 +36,18 @@ impl<T, const C: usize> Stack<T, C> {
     ///
     /// let stack = Stack::<i32, 4>::new();
     /// ```
-    pub fn new() -> Self
-    where
-        T: Copy,
-    {
-        let elements: [Option<T>; C] = [None; C];
+    pub const fn new() -> Self {
         Self {
-            elements,
+            // SAFETY: an array of `MaybeUninit<T>` does not require
+            // initialization; `length` tracks how many slots are live.
+            elements: unsafe { MaybeUninit::uninit().assume_init() },
             length: 0,
+            // SAFETY: as above; `reserved` tracks how many slots are live.
+            snapshot_storage: unsafe { MaybeUninit::uninit().assume_init() },
+            snapshot_offsets: [0; C],
+            snapshot_lengths: [0; C],
+            snapshots_length: 0,
+            reserved: 0,
         }
     }
 
@@ -78,7 +97,13 @@ impl<T, const C: usize> Stack<T, C> {
         self.len() == 0
     }
 
-    /// Returns `true` if the stack cannot contain any more elements.
+    /// Returns `true` if the stack cannot currently accept more elements.
+    ///
+    /// This also accounts for storage already committed to open snapshots
+    /// (see [`snapshot`](Self::snapshot)): a snapshot's clone and the
+    /// stack's own live elements draw from the same `C`-element budget, so
+    /// this can be `true` even while [`len`](Self::len) is less than
+    /// [`capacity`](Self::capacity).
     ///
     /// # Example
     /// ```
@@ -91,7 +116,7 @@ impl<T, const C: usize> Stack<T, C> {
     /// ```
     #[inline]
     pub const fn is_full(&self) -> bool {
-        self.len() == self.capacity()
+        self.length + self.reserved >= C
     }
 
     /// Returns a reference to the element at the given index.
@@ -111,27 +136,27 @@ impl<T, const C: usize> Stack<T, C> {
         if index >= self.len() {
             return None;
         }
-        self.elements[index].as_ref()
-    }
-
-    /// Returns the index of the last occupied slot in the stack.
-    #[inline]
-    const fn top(&self) -> usize {
-        self.len() - 1
+        // SAFETY: `index < self.length`, so this slot has been written by `push`.
+        Some(unsafe { self.elements[index].assume_init_ref() })
     }
 
-    /// Returns a reference to the underlying storage of the stack.
+    /// Returns a slice of the initialized elements in the stack, bottom to top.
     ///
     /// # Example
     /// ```
     /// use fundamental::Stack;
     ///
     /// let mut stack = Stack::<i32, 3>::new();
-    /// assert_eq!(stack.as_slice(), &[None, None, None]);
+    /// assert_eq!(stack.as_slice(), &[]);
+    /// let _ = stack.push(1);
+    /// assert_eq!(stack.as_slice(), &[1]);
+    /// let _ = stack.push(2);
+    /// assert_eq!(stack.as_slice(), &[1, 2]);
     /// ```
     #[inline]
-    pub const fn as_slice(&self) -> &[Option<T>] {
-        &self.elements
+    pub const fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `length` slots are initialized and contiguous.
+        unsafe { std::slice::from_raw_parts(self.elements.as_ptr() as *const T, self.length) }
     }
 
     /// Insert an element at the top of the stack.
@@ -142,18 +167,19 @@ impl<T, const C: usize> Stack<T, C> {
     /// use fundamental::Stack;
     ///
     /// let mut stack = Stack::<i32, 3>::new();
-    /// assert_eq!(stack.as_slice(), &[None, None, None]);
+    /// assert_eq!(stack.as_slice(), &[]);
     /// let _ = stack.push(1);
-    /// assert_eq!(stack.as_slice(), &[Some(1), None, None]);
+    /// assert_eq!(stack.as_slice(), &[1]);
     /// let _ = stack.push(2);
-    /// assert_eq!(stack.as_slice(), &[Some(1), Some(2), None]);
+    /// assert_eq!(stack.as_slice(), &[1, 2]);
     /// ```
     #[inline]
     pub fn push(&mut self, element: T) -> Result<(), T> {
         if self.is_full() {
             return Err(element);
         }
-        self.elements[self.len()] = Some(element);
+        // SAFETY: `self.length < C`, so this slot is within bounds and unoccupied.
+        unsafe { ptr::write(self.elements[self.length].as_mut_ptr(), element) };
         self.length += 1;
         Ok(())
     }
@@ -179,9 +205,254 @@ impl<T, const C: usize> Stack<T, C> {
         if self.is_empty() {
             return None;
         }
-        let element = self.elements[self.top()].take();
         self.length -= 1;
-        element
+        // SAFETY: the slot at `self.length` was initialized by `push` and has
+        // not been read since, as `self.length` now excludes it.
+        Some(unsafe { self.elements[self.length].assume_init_read() })
+    }
+
+    /// Builds a stack by pushing elements from `iter` until either it is
+    /// exhausted or the stack is full, returning the stack alongside
+    /// whatever is left of the iterator.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::Stack;
+    ///
+    /// let (stack, mut rest) = Stack::<i32, 2>::try_from_iter(1..4);
+    /// assert_eq!(stack.as_slice(), &[1, 2]);
+    /// assert_eq!(rest.next(), Some(3));
+    /// ```
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> (Self, I::IntoIter) {
+        let mut stack = Self::new();
+        let mut iter = iter.into_iter();
+        while !stack.is_full() {
+            let Some(element) = iter.next() else {
+                break;
+            };
+            // `stack` isn't full, so this always succeeds.
+            let _ = stack.push(element);
+        }
+        (stack, iter)
+    }
+}
+
+impl<T, const C: usize> Drop for Stack<T, C> {
+    fn drop(&mut self) {
+        for element in &mut self.elements[..self.length] {
+            // SAFETY: the first `length` slots are initialized.
+            unsafe { element.assume_init_drop() };
+        }
+        // Every active level's clone sits contiguously within `0..reserved`:
+        // levels are only ever appended to the end and removed from the end
+        // (restoring/clearing always targets the innermost, most recently
+        // taken snapshot), so there are no gaps to skip over.
+        for element in &mut self.snapshot_storage[..self.reserved] {
+            // SAFETY: the first `reserved` slots are initialized.
+            unsafe { element.assume_init_drop() };
+        }
+    }
+}
+
+impl<T: Clone, const C: usize> Stack<T, C> {
+    /// Pushes as many of `elements` as fit, in order, and returns the
+    /// unconsumed tail once the stack is full.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::Stack;
+    ///
+    /// let mut stack = Stack::<i32, 2>::new();
+    /// assert_eq!(stack.push_slice(&[1, 2, 3]), &[3]);
+    /// assert_eq!(stack.as_slice(), &[1, 2]);
+    /// ```
+    pub fn push_slice<'a>(&mut self, elements: &'a [T]) -> &'a [T] {
+        let mut consumed = 0;
+        for element in elements {
+            if self.push(element.clone()).is_err() {
+                break;
+            }
+            consumed += 1;
+        }
+        &elements[consumed..]
+    }
+
+    /// Starts a new, possibly nested, snapshot of the stack's current
+    /// contents. Returns `false`, taking no snapshot, if the stack is
+    /// already tracking `C` nested snapshots, or if cloning the current
+    /// contents would need more room than is left in the shared snapshot
+    /// buffer (see [`is_full`](Self::is_full)); either way, a later
+    /// [`restore`](Self::restore) unwinds to the innermost snapshot that
+    /// is actually active.
+    ///
+    /// The contents are cloned eagerly, so a later call to `restore` can put
+    /// the stack back exactly as it was, regardless of what is pushed or
+    /// popped in the meantime. Call [`clear_snapshot`](Self::clear_snapshot)
+    /// instead to commit to whatever the stack holds by then and discard
+    /// the snapshot.
+    ///
+    /// A snapshot's clone draws from the same `C`-element budget as the
+    /// stack's own contents (see [`is_full`](Self::is_full)), so while one
+    /// is active, pushing new elements can fail sooner than `capacity()`
+    /// would suggest; taking a snapshot while the stack is near `capacity()`
+    /// can likewise fail, since the clone itself needs room in that budget.
+    ///
+    /// This lets `Stack` back a recursive-descent parser: speculatively
+    /// push and pop without heap allocation, then commit or roll back.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::Stack;
+    ///
+    /// let mut stack = Stack::<i32, 3>::new();
+    /// stack.push(1);
+    /// stack.push(2);
+    /// assert!(stack.snapshot());
+    /// stack.pop();
+    /// stack.pop();
+    /// assert_eq!(stack.as_slice(), &[]);
+    /// stack.restore();
+    /// assert_eq!(stack.as_slice(), &[1, 2]);
+    /// ```
+    #[must_use = "a dropped `false` means the snapshot was not taken and a later `restore` unwinds to a shallower one"]
+    pub fn snapshot(&mut self) -> bool {
+        if self.snapshots_length == self.snapshot_lengths.len() {
+            return false;
+        }
+        if self.reserved + self.length > C {
+            return false;
+        }
+        let level = self.snapshots_length;
+        let offset = self.reserved;
+        for i in 0..self.length {
+            // SAFETY: slot `i` is live. The check above ensures `offset +
+            // length <= C`, so `offset + i` (with `i < length`) is within
+            // bounds and, as the next free offset, not in use by any other
+            // level.
+            let clone = unsafe { self.elements[i].assume_init_ref().clone() };
+            unsafe { ptr::write(self.snapshot_storage[offset + i].as_mut_ptr(), clone) };
+        }
+        self.snapshot_offsets[level] = offset;
+        self.snapshot_lengths[level] = self.length;
+        self.reserved += self.length;
+        self.snapshots_length += 1;
+        true
+    }
+
+    /// Rewinds the stack to the most recently taken snapshot, restoring its
+    /// exact contents at that point. Does nothing if no snapshot is active.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::Stack;
+    ///
+    /// let mut stack = Stack::<i32, 3>::new();
+    /// stack.push(1);
+    /// stack.push(2);
+    /// assert!(stack.snapshot());
+    /// stack.pop();
+    /// stack.pop();
+    /// assert_eq!(stack.as_slice(), &[]);
+    /// stack.restore();
+    /// assert_eq!(stack.as_slice(), &[1, 2]);
+    /// ```
+    pub fn restore(&mut self) {
+        if self.snapshots_length == 0 {
+            return;
+        }
+        self.snapshots_length -= 1;
+        let level = self.snapshots_length;
+        let offset = self.snapshot_offsets[level];
+        let len = self.snapshot_lengths[level];
+        // Free this level's reservation before pushing its elements back, so
+        // `push`'s capacity check sees the room they're entitled to.
+        self.reserved -= len;
+        while self.length > 0 {
+            self.length -= 1;
+            // SAFETY: slot `self.length` is live and hasn't been read since.
+            unsafe { self.elements[self.length].assume_init_drop() };
+        }
+        for i in 0..len {
+            // SAFETY: slot `offset + i` of `snapshot_storage` was
+            // initialized by `snapshot` and this level is only ever
+            // restored once.
+            let element = unsafe { self.snapshot_storage[offset + i].assume_init_read() };
+            // The stack was just emptied and its reservation freed above, so
+            // there is always room for the `len` elements this level holds.
+            let _ = self.push(element);
+        }
+    }
+
+    /// Commits the most recently taken snapshot: the stack keeps its
+    /// current contents, and the snapshot is discarded. Does nothing if no
+    /// snapshot is active.
+    ///
+    /// # Example
+    /// ```
+    /// use fundamental::Stack;
+    ///
+    /// let mut stack = Stack::<i32, 3>::new();
+    /// stack.push(1);
+    /// assert!(stack.snapshot());
+    /// stack.pop();
+    /// stack.clear_snapshot();
+    /// assert_eq!(stack.as_slice(), &[]);
+    /// ```
+    pub fn clear_snapshot(&mut self) {
+        if self.snapshots_length == 0 {
+            return;
+        }
+        self.snapshots_length -= 1;
+        let level = self.snapshots_length;
+        let offset = self.snapshot_offsets[level];
+        let len = self.snapshot_lengths[level];
+        for element in &mut self.snapshot_storage[offset..offset + len] {
+            // SAFETY: slots `offset..offset + len` were initialized by
+            // `snapshot` and haven't been read since.
+            unsafe { element.assume_init_drop() };
+        }
+        self.reserved -= len;
+    }
+}
+
+impl<T: Clone, const C: usize> Clone for Stack<T, C> {
+    fn clone(&self) -> Self {
+        let mut stack = Self::new();
+        for element in self.as_slice() {
+            // `self` has at most `C` elements, so this always succeeds.
+            let _ = stack.push(element.clone());
+        }
+        stack
+    }
+}
+
+impl<T: fmt::Debug, const C: usize> fmt::Debug for Stack<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Stack")
+            .field("elements", &self.as_slice())
+            .field("length", &self.length)
+            .finish()
+    }
+}
+
+impl<T, const C: usize> FromIterator<T> for Stack<T, C> {
+    /// Collects up to `C` elements from `iter`, in order; anything beyond
+    /// capacity is silently dropped. Use [`Stack::try_from_iter`] to get the
+    /// leftover iterator back instead.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::try_from_iter(iter).0
+    }
+}
+
+impl<T, const C: usize> Extend<T> for Stack<T, C> {
+    /// Pushes elements from `iter` until either it is exhausted or the stack
+    /// is full; anything beyond capacity is silently dropped.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for element in iter {
+            if self.push(element).is_err() {
+                break;
+            }
+        }
     }
 }
 
@@ -235,4 +506,185 @@ mod tests {
 
         // assert_eq!(stack.pop(), None);
     }
+
+    #[test]
+    fn drops_non_copy_elements() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let mut stack = Stack::<Rc<()>, 2>::new();
+        assert_eq!(stack.push(value.clone()), Ok(()));
+        assert_eq!(stack.push(value.clone()), Ok(()));
+        assert_eq!(Rc::strong_count(&value), 3);
+
+        drop(stack);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn holds_non_copy_elements() {
+        let mut stack = Stack::<String, 2>::new();
+        assert_eq!(stack.push(String::from("a")), Ok(()));
+        assert_eq!(stack.push(String::from("b")), Ok(()));
+        assert_eq!(stack.pop(), Some(String::from("b")));
+        assert_eq!(stack.pop(), Some(String::from("a")));
+    }
+
+    #[test]
+    fn snapshot_restore() {
+        let mut stack = Stack::<i32, 3>::new();
+        assert_eq!(stack.push(1), Ok(()));
+        assert_eq!(stack.push(2), Ok(()));
+
+        assert!(stack.snapshot());
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.as_slice(), &[]);
+
+        stack.restore();
+        assert_eq!(stack.as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn snapshot_clear() {
+        let mut stack = Stack::<i32, 3>::new();
+        assert_eq!(stack.push(1), Ok(()));
+        assert_eq!(stack.push(2), Ok(()));
+
+        assert!(stack.snapshot());
+        assert_eq!(stack.pop(), Some(2));
+
+        stack.clear_snapshot();
+        assert_eq!(stack.as_slice(), &[1]);
+
+        // There is no snapshot left to restore to.
+        stack.restore();
+        assert_eq!(stack.as_slice(), &[1]);
+    }
+
+    #[test]
+    fn nested_snapshots() {
+        let mut stack = Stack::<i32, 3>::new();
+        assert_eq!(stack.push(1), Ok(()));
+
+        assert!(stack.snapshot());
+        assert_eq!(stack.push(2), Ok(()));
+
+        assert!(stack.snapshot());
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.as_slice(), &[]);
+
+        // Restoring the inner snapshot only undoes what happened after it.
+        stack.restore();
+        assert_eq!(stack.as_slice(), &[1, 2]);
+
+        // Restoring the outer snapshot undoes the rest.
+        stack.restore();
+        assert_eq!(stack.as_slice(), &[1]);
+    }
+
+    #[test]
+    fn unrestored_snapshot_does_not_block_later_ones() {
+        let mut stack = Stack::<i32, 4>::new();
+        assert_eq!(stack.push(1), Ok(()));
+        assert_eq!(stack.push(2), Ok(()));
+
+        // Leave this snapshot open (never restored or cleared) ...
+        assert!(stack.snapshot());
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+
+        // ... and push and snapshot past it; each snapshot's clone is
+        // appended after the last, so this does not overflow the shared
+        // storage as long as the total across both levels stays within `C`.
+        assert_eq!(stack.push(3), Ok(()));
+        assert_eq!(stack.push(4), Ok(()));
+        assert!(stack.snapshot());
+        assert_eq!(stack.pop(), Some(4));
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.as_slice(), &[]);
+
+        stack.restore();
+        assert_eq!(stack.as_slice(), &[3, 4]);
+
+        stack.restore();
+        assert_eq!(stack.as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn snapshot_reserves_capacity_from_future_pushes() {
+        let mut stack = Stack::<i32, 2>::new();
+        assert_eq!(stack.push(1), Ok(()));
+        assert_eq!(stack.push(2), Ok(()));
+
+        assert!(stack.snapshot());
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+
+        // The snapshot's clone already uses up the stack's entire `C = 2`
+        // budget, so there is no room for new elements until it is resolved,
+        // even though the stack itself currently holds none.
+        assert_eq!(stack.is_full(), true);
+        assert_eq!(stack.push(3), Err(3));
+
+        stack.restore();
+        assert_eq!(stack.as_slice(), &[1, 2]);
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.push(3), Ok(()));
+    }
+
+    #[test]
+    fn snapshot_reports_failure_past_max_nesting() {
+        let mut stack = Stack::<i32, 2>::new();
+        assert!(stack.snapshot());
+        assert!(stack.snapshot());
+        assert_eq!(stack.snapshot(), false);
+    }
+
+    #[test]
+    fn snapshot_reports_failure_when_storage_is_exhausted() {
+        // Two snapshots of the same, unpopped contents each need their own
+        // `length`-sized copy, so back-to-back snapshots can run out of
+        // shared storage well before hitting the nesting limit.
+        let mut stack = Stack::<i32, 2>::new();
+        assert_eq!(stack.push(1), Ok(()));
+        assert_eq!(stack.push(2), Ok(()));
+
+        assert!(stack.snapshot());
+        assert_eq!(stack.snapshot(), false);
+
+        // The first snapshot is still intact.
+        stack.restore();
+        assert_eq!(stack.as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn push_slice_stops_at_capacity() {
+        let mut stack = Stack::<i32, 2>::new();
+        assert_eq!(stack.push_slice(&[1, 2, 3]), &[3]);
+        assert_eq!(stack.as_slice(), &[1, 2]);
+        assert_eq!(stack.push_slice(&[4]), &[4]);
+    }
+
+    #[test]
+    fn try_from_iter_returns_leftovers() {
+        let (stack, mut rest) = Stack::<i32, 2>::try_from_iter(1..4);
+        assert_eq!(stack.as_slice(), &[1, 2]);
+        assert_eq!(rest.next(), Some(3));
+        assert_eq!(rest.next(), None);
+    }
+
+    #[test]
+    fn from_iter_drops_the_overflow() {
+        let stack: Stack<i32, 2> = (1..4).collect();
+        assert_eq!(stack.as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn extend_stops_at_capacity() {
+        let mut stack = Stack::<i32, 2>::new();
+        stack.extend(1..4);
+        assert_eq!(stack.as_slice(), &[1, 2]);
+    }
 }